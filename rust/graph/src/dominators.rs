@@ -0,0 +1,164 @@
+use super::igraph::IGraph;
+use super::NodeRef;
+
+// Computes the immediate dominator of every node reachable from `root`,
+// using the Cooper-Harvey-Kennedy "simple, fast" iterative algorithm. The
+// result is indexed by NodeRef; unreachable nodes are left as None.
+pub fn dominators(g: &IGraph, root: NodeRef) -> Vec<Option<NodeRef>> {
+    let (order, rpo) = reverse_postorder(g, root);
+
+    let mut idom: Vec<Option<NodeRef>> = vec![None; g.size()];
+    idom[*root] = Some(root);
+
+    // iterate in reverse postorder, skipping the root, until a full pass
+    // makes no changes to any idom entry.
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for &b in order.iter().skip(1) {
+            let mut new_idom = None;
+
+            // pick the first already-processed predecessor, then fold in
+            // the rest with intersect.
+            for &p in g.parents(b).iter() {
+                if idom[*p].is_none() {
+                    continue;
+                }
+
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(cur) => intersect(&idom, &rpo, p, cur),
+                });
+            }
+
+            if idom[*b] != new_idom {
+                idom[*b] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    idom
+}
+
+// walks two fingers up the partially-built dominator tree, advancing
+// whichever one has the larger reverse-postorder number, until they meet.
+fn intersect(idom: &[Option<NodeRef>], rpo: &[usize], a: NodeRef, b: NodeRef) -> NodeRef {
+    let mut finger1 = a;
+    let mut finger2 = b;
+
+    while finger1 != finger2 {
+        while rpo[*finger1] > rpo[*finger2] {
+            finger1 = idom[*finger1].unwrap();
+        }
+        while rpo[*finger2] > rpo[*finger1] {
+            finger2 = idom[*finger2].unwrap();
+        }
+    }
+
+    finger1
+}
+
+// runs an iterative (explicit-stack) postorder DFS from root using
+// `children`, then reverses it to get the reverse-postorder numbering used
+// by `dominators`. Returns the reachable nodes in reverse-postorder order
+// along with a lookup from NodeRef to its position in that order.
+fn reverse_postorder(g: &IGraph, root: NodeRef) -> (Vec<NodeRef>, Vec<usize>) {
+    let mut visited = vec![false; g.size()];
+    let mut postorder = Vec::new();
+    let mut stack: Vec<(NodeRef, Vec<NodeRef>)> = Vec::new();
+
+    visited[*root] = true;
+    stack.push((root, g.children(root).iter().cloned().collect()));
+
+    while let Some(&mut (node, ref mut children)) = stack.last_mut() {
+        match children.pop() {
+            Some(child) => {
+                if !visited[*child] {
+                    visited[*child] = true;
+                    stack.push((child, g.children(child).iter().cloned().collect()));
+                }
+            }
+            None => {
+                postorder.push(node);
+                stack.pop();
+            }
+        }
+    }
+
+    postorder.reverse();
+    let order = postorder;
+
+    let mut rpo = vec![usize::max_value(); g.size()];
+    for (i, &n) in order.iter().enumerate() {
+        rpo[*n] = i;
+    }
+
+    (order, rpo)
+}
+
+// builds the dominator tree as an IGraph with the same NodeRefs as the
+// input graph: an edge from each node's immediate dominator to the node
+// itself. The root has no incoming edge.
+pub fn dominator_tree(g: &IGraph, idom: &[Option<NodeRef>]) -> IGraph {
+    let mut tree = IGraph::new();
+    for _ in 0..g.size() {
+        tree.create_node();
+    }
+
+    for (n, parent) in idom.iter().enumerate() {
+        if let Some(parent) = *parent {
+            if *parent != n {
+                tree.add_edge(parent, n.into());
+            }
+        }
+    }
+
+    tree
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // the classic diamond CFG: 0 -> {1, 2} -> 3 -> 4. 3's two predecessors
+    // only share 0 as a common dominator, so idom[3] is 0, not 1 or 2.
+    fn diamond() -> IGraph {
+        let mut g = IGraph::new();
+        for _ in 0..5 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(0.into(), 2.into());
+        g.add_edge(1.into(), 3.into());
+        g.add_edge(2.into(), 3.into());
+        g.add_edge(3.into(), 4.into());
+        g
+    }
+
+    #[test]
+    fn idom_of_diamond() {
+        let g = diamond();
+        let idom = dominators(&g, 0.into());
+
+        assert_eq!(idom[0], Some(0.into()));
+        assert_eq!(idom[1], Some(0.into()));
+        assert_eq!(idom[2], Some(0.into()));
+        assert_eq!(idom[3], Some(0.into()));
+        assert_eq!(idom[4], Some(3.into()));
+    }
+
+    #[test]
+    fn dominator_tree_of_diamond() {
+        let g = diamond();
+        let idom = dominators(&g, 0.into());
+        let tree = dominator_tree(&g, &idom);
+
+        assert!(tree.children(0.into()).contains(&1.into()));
+        assert!(tree.children(0.into()).contains(&2.into()));
+        assert!(tree.children(0.into()).contains(&3.into()));
+        assert!(tree.children(3.into()).contains(&4.into()));
+        assert!(tree.parents(0.into()).is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+use super::igraph::IGraph;
+use super::NodeRef;
+
+#[cfg(test)]
+use super::toposort::toposort;
+
+use std::collections::HashSet;
+
+// computes a greedy feedback arc set (the Eades-Lin-Smyth heuristic):
+// removing these edges leaves the graph acyclic. Useful when a caller
+// needs a DAG but the input, as discovered by the existing Tarjan SCC
+// pass, has cycles.
+pub fn feedback_arc_set(g: &IGraph) -> HashSet<(NodeRef, NodeRef)> {
+    let order = linear_arrangement(g);
+
+    let mut position = vec![0; g.size()];
+    for (i, &n) in order.iter().enumerate() {
+        position[*n] = i;
+    }
+
+    // any edge pointing from a later node to an earlier one in the
+    // arrangement is "backward", and is exactly the feedback arc set. A
+    // self-loop is always its own 1-cycle, but never sits "backward" of
+    // itself in the arrangement, so it needs to be added explicitly.
+    let mut fas = HashSet::new();
+    for n in (0..g.size()).map(|x| NodeRef::from(x)) {
+        for &child in g.children(n).iter() {
+            if child == n || position[*n] > position[*child] {
+                fas.insert((n, child));
+            }
+        }
+    }
+
+    fas
+}
+
+// builds a left-to-right ordering of all nodes using the greedy
+// linear-arrangement heuristic: repeatedly peel off sinks (prepended to the
+// right-hand run), then sources (appended to the left-hand run), and
+// otherwise the node maximizing out-degree minus in-degree (also appended
+// to the left-hand run).
+fn linear_arrangement(g: &IGraph) -> Vec<NodeRef> {
+    let mut out_degree: Vec<usize> = (0..g.size()).map(|n| g.children(n.into()).len()).collect();
+    let mut in_degree: Vec<usize> = (0..g.size()).map(|n| g.parents(n.into()).len()).collect();
+    let mut removed = vec![false; g.size()];
+    let mut remaining = g.size();
+
+    let mut left = Vec::with_capacity(g.size());
+    let mut right = Vec::with_capacity(g.size());
+
+    while remaining > 0 {
+        // peel off every sink.
+        while let Some(n) = (0..g.size())
+            .map(|x| NodeRef::from(x))
+            .find(|&n| !removed[*n] && out_degree[*n] == 0)
+        {
+            remove(n, g, &mut removed, &mut out_degree, &mut in_degree);
+            right.push(n);
+            remaining -= 1;
+        }
+
+        // peel off every source.
+        while let Some(n) = (0..g.size())
+            .map(|x| NodeRef::from(x))
+            .find(|&n| !removed[*n] && in_degree[*n] == 0)
+        {
+            remove(n, g, &mut removed, &mut out_degree, &mut in_degree);
+            left.push(n);
+            remaining -= 1;
+        }
+
+        // otherwise take the node maximizing out-degree minus in-degree.
+        if remaining > 0 {
+            let best = (0..g.size())
+                .map(|x| NodeRef::from(x))
+                .filter(|&n| !removed[*n])
+                .max_by_key(|&n| out_degree[*n] as isize - in_degree[*n] as isize)
+                .unwrap();
+
+            remove(best, g, &mut removed, &mut out_degree, &mut in_degree);
+            left.push(best);
+            remaining -= 1;
+        }
+    }
+
+    right.reverse();
+    left.extend(right);
+    left
+}
+
+// marks `n` removed and keeps the remaining nodes' degree counts in sync.
+fn remove(n: NodeRef, g: &IGraph, removed: &mut [bool], out_degree: &mut [usize], in_degree: &mut [usize]) {
+    removed[*n] = true;
+
+    for &p in g.parents(n).iter() {
+        if !removed[*p] {
+            out_degree[*p] -= 1;
+        }
+    }
+
+    for &c in g.children(n).iter() {
+        if !removed[*c] {
+            in_degree[*c] -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn removing_the_set_leaves_a_cycle_acyclic() {
+        let mut g = IGraph::new();
+        for _ in 0..3 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(1.into(), 2.into());
+        g.add_edge(2.into(), 0.into());
+
+        let fas = feedback_arc_set(&g);
+        assert!(!fas.is_empty());
+
+        for &(from, to) in fas.iter() {
+            g.remove_edge(from, to);
+        }
+
+        assert!(toposort(&g).is_ok());
+    }
+
+    #[test]
+    fn self_loop_is_always_in_the_set() {
+        let mut g = IGraph::new();
+        g.create_node();
+        g.add_edge(0.into(), 0.into());
+
+        let fas = feedback_arc_set(&g);
+        assert!(fas.contains(&(0.into(), 0.into())));
+    }
+}
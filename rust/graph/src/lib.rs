@@ -1,11 +1,24 @@
 use std::ops::Deref;
 
+mod dominators;
+mod feedback_arc_set;
 mod igraph;
 mod scc;
+mod toposort;
+mod transitive;
+mod union_find;
 mod vgraph;
+mod wgraph;
 
+pub use dominators::{dominators, dominator_tree};
+pub use feedback_arc_set::feedback_arc_set;
 pub use igraph::*;
-pub use scc::scc;
+pub use scc::{condense, scc};
+pub use toposort::toposort;
+pub use transitive::{transitive_closure, transitive_reduction};
+pub use union_find::{connected_components, num_components};
+pub use vgraph::VGraph;
+pub use wgraph::{dijkstra, WGraph, Zero};
 
 #[derive(Debug,Default,PartialEq,Eq,Copy,Clone,Hash)]
 pub struct NodeRef(usize);
@@ -2,10 +2,11 @@ use super::igraph::IGraph;
 use super::NodeRef;
 
 use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 
-#[derive(Debug,Clone)]
+#[derive(Debug,Clone,PartialEq,Eq)]
 // A Strongly Connected Component is either a group of nodes in a cycle or a
 // single node.
 pub enum SCC<T> where T: Debug + Clone + Eq + Hash {
@@ -23,6 +24,30 @@ impl<T> SCC<T> where T: Debug + Clone + Eq + Hash {
     }
 }
 
+// HashSet has no Hash impl of its own (its iteration order isn't
+// canonical), so Group's hash is folded together with xor to stay
+// order-independent; Single is tagged separately so it can't collide with
+// a Group holding the same lone element.
+impl<T> Hash for SCC<T> where T: Debug + Clone + Eq + Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            SCC::Single(ref n) => {
+                0u8.hash(state);
+                n.hash(state);
+            }
+            SCC::Group(ref group) => {
+                1u8.hash(state);
+                let combined = group.iter().fold(0u64, |acc, n| {
+                    let mut h = DefaultHasher::new();
+                    n.hash(&mut h);
+                    acc ^ h.finish()
+                });
+                combined.hash(state);
+            }
+        }
+    }
+}
+
 // State for Tarjan's SCC algorithm
 pub struct Tarjan<'a> {
     meta: Vec<(bool, usize)>,
@@ -204,3 +229,74 @@ impl<'a> Tarjan<'a> {
 pub fn scc(g: &IGraph) -> Vec<SCC<NodeRef>> {
     Tarjan::new(g).scc()
 }
+
+// collapses each strongly connected component into a single node, returning
+// the acyclic quotient graph and a mapping from each original node to the
+// component node it was folded into.
+pub fn condense(g: &IGraph, sccs: &[SCC<NodeRef>]) -> (IGraph, Vec<NodeRef>) {
+    // assign every original node the NodeRef of its component.
+    let mut component = IGraph::new();
+    let mut of: Vec<NodeRef> = vec![NodeRef::default(); g.size()];
+
+    for c in sccs.iter() {
+        let cn = component.create_node();
+        match *c {
+            SCC::Single(n) => of[*n] = cn,
+            SCC::Group(ref group) => {
+                for &n in group.iter() {
+                    of[*n] = cn;
+                }
+            }
+        }
+    }
+
+    // add an inter-component edge for every original edge that crosses a
+    // component boundary; the HashSet edge storage dedups the rest.
+    for nr in (0..g.size()).map(|x| x.into()) {
+        for &child in g.children(nr).iter() {
+            let (from, to) = (of[*nr], of[*child]);
+            if from != to {
+                component.add_edge(from, to);
+            }
+        }
+    }
+
+    (component, of)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // a 3-node cycle (0 -> 1 -> 2 -> 0) feeding into an unrelated node 3.
+    fn cycle_into_tail() -> IGraph {
+        let mut g = IGraph::new();
+        for _ in 0..4 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(1.into(), 2.into());
+        g.add_edge(2.into(), 0.into());
+        g.add_edge(2.into(), 3.into());
+        g
+    }
+
+    #[test]
+    fn condense_collapses_cycle_into_one_node() {
+        let g = cycle_into_tail();
+        let sccs = scc(&g);
+        let (quotient, of) = condense(&g, &sccs);
+
+        // the cycle's three nodes all land in the same component, and node
+        // 3 lands in a different one.
+        assert_eq!(of[0], of[1]);
+        assert_eq!(of[1], of[2]);
+        assert_ne!(of[0], of[3]);
+
+        // the quotient graph has exactly one node per component and a
+        // single edge from the cycle's component to node 3's.
+        assert_eq!(quotient.size(), 2);
+        assert_eq!(quotient.children(of[0]).iter().collect::<Vec<_>>(), vec![&of[3]]);
+        assert!(quotient.children(of[3]).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use super::igraph::IGraph;
+use super::NodeRef;
+use super::scc::{self, SCC};
+
+use std::collections::VecDeque;
+
+// returns the nodes of an acyclic graph in topological order. If the graph
+// has cycles, returns the offending groups (found via the existing Tarjan
+// SCC pass) instead of a bare failure.
+pub fn toposort(g: &IGraph) -> Result<Vec<NodeRef>, Vec<SCC<NodeRef>>> {
+    // self-loops aren't cycles anywhere else in this crate (scc's Tarjan
+    // pass ignores them too), so they're excluded here rather than counted
+    // as an in-edge that can never be satisfied.
+    let mut indegree: Vec<usize> = (0..g.size())
+        .map(|n| {
+            let n = NodeRef::from(n);
+            g.parents(n).iter().filter(|&&p| p != n).count()
+        })
+        .collect();
+
+    // seed the queue with every zero-in-degree node.
+    let mut queue: VecDeque<NodeRef> = indegree.iter().enumerate()
+        .filter(|&(_, &d)| d == 0)
+        .map(|(n, _)| n.into())
+        .collect();
+
+    let mut order = Vec::with_capacity(g.size());
+
+    while let Some(n) = queue.pop_front() {
+        order.push(n);
+
+        for &child in g.children(n).iter() {
+            if child == n {
+                continue;
+            }
+
+            indegree[*child] -= 1;
+            if indegree[*child] == 0 {
+                queue.push_back(child);
+            }
+        }
+    }
+
+    if order.len() == g.size() {
+        return Ok(order);
+    }
+
+    // some nodes never hit zero in-degree, so the graph has a cycle; reuse
+    // the SCC pass to report the groups responsible instead of a bare bool.
+    let cycles = scc::scc(g).into_iter()
+        .filter(|c| match *c {
+            SCC::Group(ref group) => group.len() > 1,
+            SCC::Single(_) => false,
+        })
+        .collect();
+
+    Err(cycles)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn orders_a_dag() {
+        let mut g = IGraph::new();
+        for _ in 0..3 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(1.into(), 2.into());
+
+        let order = toposort(&g).expect("acyclic graph should sort");
+        let position = |n: NodeRef| order.iter().position(|&x| x == n).unwrap();
+
+        assert_eq!(order.len(), 3);
+        assert!(position(0.into()) < position(1.into()));
+        assert!(position(1.into()) < position(2.into()));
+    }
+
+    #[test]
+    fn reports_the_cycle() {
+        let mut g = IGraph::new();
+        for _ in 0..3 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(1.into(), 2.into());
+        g.add_edge(2.into(), 0.into());
+
+        let cycles = toposort(&g).unwrap_err();
+        assert_eq!(cycles.len(), 1);
+        match cycles[0] {
+            SCC::Group(ref group) => assert_eq!(group.len(), 3),
+            SCC::Single(_) => panic!("expected a Group"),
+        }
+    }
+
+    #[test]
+    fn self_loop_alone_is_not_a_cycle() {
+        let mut g = IGraph::new();
+        for _ in 0..3 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 0.into());
+        g.add_edge(1.into(), 2.into());
+
+        let order = toposort(&g).expect("a self-loop shouldn't block toposort");
+        assert_eq!(order.len(), 3);
+    }
+}
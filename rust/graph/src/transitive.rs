@@ -0,0 +1,104 @@
+use super::igraph::IGraph;
+use super::NodeRef;
+use super::toposort::toposort;
+
+use std::collections::HashSet;
+
+// removes every edge u -> v implied by a longer u -> v path, yielding the
+// unique minimal graph with the same reachability. The input must be
+// acyclic; pair it with toposort/condense to get there from a cyclic graph.
+pub fn transitive_reduction(g: &IGraph) -> IGraph {
+    let order = toposort(g).expect("transitive_reduction requires an acyclic graph");
+    let reach = reachable_sets(g, &order);
+
+    let mut reduced = IGraph::new();
+    for _ in 0..g.size() {
+        reduced.create_node();
+    }
+
+    // process in reverse topological order, keeping u -> v only if v isn't
+    // already reachable through one of u's other successors.
+    for &u in order.iter().rev() {
+        for &v in g.children(u).iter() {
+            let redundant = g.children(u).iter()
+                .any(|&w| w != v && reach[*w].contains(&v));
+            if !redundant {
+                reduced.add_edge(u, v);
+            }
+        }
+    }
+
+    reduced
+}
+
+// the dual of transitive_reduction: every node gains a direct edge to
+// every node reachable from it, not just its immediate children.
+pub fn transitive_closure(g: &IGraph) -> IGraph {
+    let order = toposort(g).expect("transitive_closure requires an acyclic graph");
+    let reach = reachable_sets(g, &order);
+
+    let mut closure = IGraph::new();
+    for _ in 0..g.size() {
+        closure.create_node();
+    }
+
+    for u in (0..g.size()).map(|x| NodeRef::from(x)) {
+        for &v in reach[*u].iter() {
+            closure.add_edge(u, v);
+        }
+    }
+
+    closure
+}
+
+// processes nodes in reverse topological order, building each node's set
+// of strict descendants from the already-finished sets of its children.
+fn reachable_sets(g: &IGraph, order: &[NodeRef]) -> Vec<HashSet<NodeRef>> {
+    let mut reach: Vec<HashSet<NodeRef>> = vec![HashSet::new(); g.size()];
+
+    for &u in order.iter().rev() {
+        for &v in g.children(u).iter() {
+            reach[*u].insert(v);
+            let descendants: Vec<NodeRef> = reach[*v].iter().cloned().collect();
+            reach[*u].extend(descendants);
+        }
+    }
+
+    reach
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -> 2 is redundant: it's already implied by 0 -> 1 -> 2.
+    fn redundant_edge() -> IGraph {
+        let mut g = IGraph::new();
+        for _ in 0..3 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(1.into(), 2.into());
+        g.add_edge(0.into(), 2.into());
+        g
+    }
+
+    #[test]
+    fn reduction_drops_the_redundant_edge() {
+        let reduced = transitive_reduction(&redundant_edge());
+
+        assert!(reduced.children(0.into()).contains(&1.into()));
+        assert!(reduced.children(1.into()).contains(&2.into()));
+        assert!(!reduced.children(0.into()).contains(&2.into()));
+    }
+
+    #[test]
+    fn closure_adds_every_reachable_pair() {
+        let closure = transitive_closure(&redundant_edge());
+
+        assert!(closure.children(0.into()).contains(&1.into()));
+        assert!(closure.children(0.into()).contains(&2.into()));
+        assert!(closure.children(1.into()).contains(&2.into()));
+        assert!(closure.children(2.into()).is_empty());
+    }
+}
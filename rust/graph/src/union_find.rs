@@ -0,0 +1,126 @@
+use super::igraph::IGraph;
+use super::NodeRef;
+
+// A disjoint-set structure over `0..size` with path compression and union
+// by rank.
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(size: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        // walk to the root, then walk again pointing every node on the path
+        // directly at it.
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut cur = x;
+        while self.parent[cur] != root {
+            let next = self.parent[cur];
+            self.parent[cur] = root;
+            cur = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return;
+        }
+
+        if self.rank[ra] < self.rank[rb] {
+            self.parent[ra] = rb;
+        } else if self.rank[ra] > self.rank[rb] {
+            self.parent[rb] = ra;
+        } else {
+            self.parent[rb] = ra;
+            self.rank[ra] += 1;
+        }
+    }
+}
+
+// labels each node with the id of its weakly connected component, treating
+// every edge as undirected.
+pub fn connected_components(g: &IGraph) -> Vec<usize> {
+    let mut uf = UnionFind::new(g.size());
+
+    for n in (0..g.size()).map(|x| NodeRef::from(x)) {
+        for &child in g.children(n).iter() {
+            uf.union(*n, *child);
+        }
+    }
+
+    // flatten the forest so every node points directly at its root, then
+    // relabel roots to dense, 0-based component ids.
+    let mut labels = vec![0; g.size()];
+    let mut component_of_root: Vec<Option<usize>> = vec![None; g.size()];
+    let mut next_id = 0;
+
+    for i in 0..g.size() {
+        let root = uf.find(i);
+        let id = match component_of_root[root] {
+            Some(id) => id,
+            None => {
+                let id = next_id;
+                component_of_root[root] = Some(id);
+                next_id += 1;
+                id
+            }
+        };
+        labels[i] = id;
+    }
+
+    labels
+}
+
+// the number of weakly connected components in `g`.
+pub fn num_components(g: &IGraph) -> usize {
+    connected_components(g).iter().max().map(|m| m + 1).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // two directed components that aren't connected to each other: 0 -> 1
+    // -> 2, and a separate pair 3 -> 4.
+    fn two_islands() -> IGraph {
+        let mut g = IGraph::new();
+        for _ in 0..5 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into());
+        g.add_edge(1.into(), 2.into());
+        g.add_edge(3.into(), 4.into());
+        g
+    }
+
+    #[test]
+    fn labels_weakly_connected_components() {
+        let g = two_islands();
+        let labels = connected_components(&g);
+
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[1], labels[2]);
+        assert_eq!(labels[3], labels[4]);
+        assert_ne!(labels[0], labels[3]);
+    }
+
+    #[test]
+    fn counts_components() {
+        let g = two_islands();
+        assert_eq!(num_components(&g), 2);
+    }
+}
@@ -1,6 +1,8 @@
 use igraph::IGraph;
 use NodeRef;
+use scc::{self, SCC};
 use std::collections::HashMap;
+use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 
@@ -54,7 +56,7 @@ impl<T> VGraph<T> where T: Eq + Hash + Clone {
             Some(nr) => nr,
             None => { return None },
         };
-        let to_r = match self.node(from) {
+        let to_r = match self.node(to) {
             Some(nr) => nr,
             None => { return None },
         };
@@ -63,4 +65,70 @@ impl<T> VGraph<T> where T: Eq + Hash + Clone {
 
         Some(())
     }
+
+    // collapses each strongly connected component into a single node,
+    // returning a VGraph of the quotient graph whose values are the SCCs of
+    // the caller's original values.
+    pub fn condense(&self) -> VGraph<SCC<T>> where T: Debug {
+        let sccs = scc::scc(&self.igraph);
+        let (quotient, _) = scc::condense(&self.igraph, &sccs);
+
+        let mut out: VGraph<SCC<T>> = VGraph::new();
+        for c in sccs {
+            out.create_node(c.map(|n| self.value(n)));
+        }
+
+        // condense() and the loop above both walk `sccs` in order, so the
+        // i'th SCC became the i'th node in both `quotient` and `out`; reuse
+        // that correspondence to copy over the quotient's edges.
+        for from in (0..quotient.size()).map(|x| NodeRef::from(x)) {
+            for &to in quotient.children(from).iter() {
+                out.deref_mut().add_edge(from, to);
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // a 3-node cycle ("a" -> "b" -> "c" -> "a") feeding into an unrelated
+    // node "d".
+    fn cycle_into_tail() -> VGraph<String> {
+        let mut g: VGraph<String> = VGraph::new();
+        for s in &["a", "b", "c", "d"] {
+            g.create_node(s.to_string());
+        }
+        g.add_edge(&"a".to_string(), &"b".to_string());
+        g.add_edge(&"b".to_string(), &"c".to_string());
+        g.add_edge(&"c".to_string(), &"a".to_string());
+        g.add_edge(&"c".to_string(), &"d".to_string());
+        g
+    }
+
+    #[test]
+    fn condense_keeps_original_values() {
+        let g = cycle_into_tail();
+        let condensed = g.condense();
+
+        assert_eq!(condensed.size(), 2);
+
+        let group = (0..condensed.size())
+            .map(|i| NodeRef::from(i))
+            .find(|&nr| match condensed.value(nr) {
+                SCC::Group(set) => set.contains("a"),
+                SCC::Single(_) => false,
+            })
+            .expect("cycle should have condensed into a Group");
+
+        let single = (0..condensed.size())
+            .map(|i| NodeRef::from(i))
+            .find(|&nr| condensed.value(nr) == SCC::Single("d".to_string()))
+            .expect("\"d\" should have survived as its own component");
+
+        assert!(condensed.children(group).contains(&single));
+    }
 }
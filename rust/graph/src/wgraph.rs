@@ -0,0 +1,155 @@
+use super::NodeRef;
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Add;
+
+// Minimal zero-element trait so dijkstra doesn't need an external numeric
+// crate; implement it for whatever Cost type the caller plugs in.
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+macro_rules! impl_zero {
+    ($($t:ty)*) => {
+        $(impl Zero for $t { fn zero() -> $t { 0 as $t } })*
+    };
+}
+
+impl_zero!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+// An edge-weighted graph: the same per-node adjacency shape as IGraph, but
+// each edge carries a Cost instead of being a bare HashSet member. Kept
+// separate from IGraph so the plain unweighted path is untouched.
+#[derive(Debug,Clone)]
+pub struct WGraph<Cost> {
+    edge_out: Vec<HashMap<NodeRef, Cost>>,
+    edge_in: Vec<HashMap<NodeRef, Cost>>,
+}
+
+impl<Cost> WGraph<Cost> where Cost: Copy {
+    pub fn new() -> WGraph<Cost> {
+        WGraph {
+            edge_out: vec![],
+            edge_in: vec![],
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.edge_out.len()
+    }
+
+    pub fn create_node(&mut self) -> NodeRef {
+        self.edge_out.push(HashMap::new());
+        self.edge_in.push(HashMap::new());
+        NodeRef(self.size() - 1)
+    }
+
+    pub fn add_edge(&mut self, from: NodeRef, to: NodeRef, weight: Cost) {
+        self.edge_out[*from].insert(to, weight);
+        self.edge_in[*to].insert(from, weight);
+    }
+
+    pub fn remove_edge(&mut self, from: NodeRef, to: NodeRef) {
+        self.edge_out[*from].remove(&to);
+        self.edge_in[*to].remove(&from);
+    }
+
+    pub fn children(&self, node: NodeRef) -> &HashMap<NodeRef, Cost> {
+        &self.edge_out[*node]
+    }
+
+    pub fn parents(&self, node: NodeRef) -> &HashMap<NodeRef, Cost> {
+        &self.edge_in[*node]
+    }
+}
+
+// wraps a (cost, node) pair so a BinaryHeap, which is normally a max-heap,
+// pops the smallest cost first.
+struct MinScored<Cost>(Cost, NodeRef);
+
+impl<Cost: PartialEq> PartialEq for MinScored<Cost> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Cost: PartialEq> Eq for MinScored<Cost> {}
+
+impl<Cost: PartialOrd> PartialOrd for MinScored<Cost> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl<Cost: Ord> Ord for MinScored<Cost> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+// computes the minimum cost from `source` to every reachable node; nodes
+// `source` can't reach are left as None. Negative weights are out of scope.
+pub fn dijkstra<Cost>(g: &WGraph<Cost>, source: NodeRef) -> Vec<Option<Cost>>
+    where Cost: Ord + Add<Output = Cost> + Zero + Copy
+{
+    let mut dist: Vec<Option<Cost>> = vec![None; g.size()];
+    let mut frontier = BinaryHeap::new();
+
+    dist[*source] = Some(Cost::zero());
+    frontier.push(MinScored(Cost::zero(), source));
+
+    while let Some(MinScored(cost, node)) = frontier.pop() {
+        // stale entry: this node was already settled with a better cost
+        // since this one was pushed, so there's nothing left to relax.
+        if let Some(best) = dist[*node] {
+            if cost > best {
+                continue;
+            }
+        }
+
+        for (&child, &weight) in g.children(node).iter() {
+            let next_cost = cost + weight;
+            let better = match dist[*child] {
+                None => true,
+                Some(d) => next_cost < d,
+            };
+
+            if better {
+                dist[*child] = Some(next_cost);
+                frontier.push(MinScored(next_cost, child));
+            }
+        }
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finds_shortest_paths() {
+        let mut g: WGraph<u32> = WGraph::new();
+        for _ in 0..5 {
+            g.create_node();
+        }
+        g.add_edge(0.into(), 1.into(), 1);
+        g.add_edge(0.into(), 2.into(), 4);
+        g.add_edge(1.into(), 2.into(), 1);
+        g.add_edge(2.into(), 3.into(), 1);
+        g.add_edge(1.into(), 3.into(), 5);
+        // node 4 is left unreachable from 0.
+
+        let dist = dijkstra(&g, 0.into());
+
+        assert_eq!(dist[0], Some(0));
+        assert_eq!(dist[1], Some(1));
+        // the 0 -> 1 -> 2 path (cost 2) beats the direct 0 -> 2 edge (cost 4).
+        assert_eq!(dist[2], Some(2));
+        // the 0 -> 1 -> 2 -> 3 path (cost 3) beats 0 -> 1 -> 3 (cost 6).
+        assert_eq!(dist[3], Some(3));
+        assert_eq!(dist[4], None);
+    }
+}